@@ -0,0 +1,11 @@
+mod admin;
+mod batch_operations;
+mod edge_cases;
+mod events;
+mod integration_scenarios;
+mod migration;
+mod multi_asset_rewards;
+mod reward_brackets;
+mod rewards;
+mod staking;
+mod streak;
@@ -1,4 +1,5 @@
 use crate::utils::*;
+use fuels::prelude::*;
 use fuels::types::ContractId;
 use fuels::types::bech32::Bech32ContractId;
 #[tokio::test]
@@ -85,4 +86,52 @@ async fn test_batch_stake_partial_failure() {
         .await;
 
     assert!(result.is_err(), "Should fail on invalid NFT in batch");
+}
+
+#[tokio::test]
+async fn test_batch_stake_resumable_processes_large_batch_across_calls() {
+    let (contract, wallets) = setup_test().await;
+    let user = &wallets[1];
+
+    // More than `batch_stake`'s 100-item cap, staked across resumed calls.
+    let nft_ids: Vec<ContractId> = (1..=150).map(create_test_nft_id).collect();
+
+    let status = contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .batch_stake_resumable(nft_ids.clone(), false)
+        .with_tx_policies(TxPolicies::default().with_script_gas_limit(200_000))
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let mut processed = match status {
+        OperationStatus::Completed => 150,
+        OperationStatus::Interrupted(n) => n,
+    };
+    assert!(processed < 150, "Low gas limit should interrupt the batch");
+
+    // Resume with an empty list until the cursor reports completion.
+    while processed < 150 {
+        let status = contract
+            .clone()
+            .with_wallet(user.clone())
+            .methods()
+            .batch_stake_resumable(vec![], false)
+            .with_tx_policies(TxPolicies::default().with_script_gas_limit(200_000))
+            .call()
+            .await
+            .unwrap()
+            .value;
+
+        processed = match status {
+            OperationStatus::Completed => 150,
+            OperationStatus::Interrupted(n) => n,
+        };
+    }
+
+    let total_staked = contract.clone().methods().get_total_staked().call().await.unwrap().value;
+    assert_eq!(total_staked, 150, "All NFTs should eventually be staked");
 } 
\ No newline at end of file
@@ -29,7 +29,9 @@ async fn test_full_staking_cycle() {
         .unwrap();
         
     // 3. Wait for rewards
-    std::thread::sleep(std::time::Duration::from_secs(SECONDS_PER_DAY));
+    // Long enough to accrue whole payable units at 10% and to clear the
+    // lock period plus withdrawal cooldown before unstaking.
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY * 10).await;
     
     // 4. Check and claim rewards
     let rewards = contract
@@ -39,8 +41,8 @@ async fn test_full_staking_cycle() {
         .await
         .unwrap()
         .value;
-        
-    assert!(rewards > 0, "Should have accumulated rewards");
+
+    assert!(base_reward(&rewards) > 0, "Should have accumulated rewards");
     
     // 5. Unstake
     contract
@@ -0,0 +1,68 @@
+use crate::utils::*;
+
+#[tokio::test]
+async fn test_migrate_advances_version_and_heals_defaults() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+
+    assert_eq!(
+        contract.clone().methods().get_storage_version().call().await.unwrap().value,
+        0,
+        "A fresh deployment should start on version 0"
+    );
+
+    // Simulate a pre-streak-feature deployment where the multiplier cap
+    // was left at its zero sentinel.
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_streak_params(SECONDS_PER_DAY, 100, 0)
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .migrate()
+        .call()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        contract.clone().methods().get_storage_version().call().await.unwrap().value,
+        1,
+        "migrate() should advance the storage version"
+    );
+    assert_eq!(
+        contract.clone().methods().get_max_streak_multiplier().call().await.unwrap().value,
+        1000,
+        "migrate() should heal a zeroed multiplier cap to the neutral default"
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_twice_reverts() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .migrate()
+        .call()
+        .await
+        .unwrap();
+
+    let result = contract
+        .with_wallet(deployer.clone())
+        .methods()
+        .migrate()
+        .call()
+        .await;
+
+    assert!(result.is_err(), "Migrating an already-current version should revert");
+}
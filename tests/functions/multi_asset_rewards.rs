@@ -0,0 +1,181 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+use crate::utils::*;
+
+#[tokio::test]
+async fn test_multi_asset_rewards_accrue_and_pay_out_independently() {
+    let extra_asset = AssetId::new([7u8; 32]);
+    let (contract, wallets) = setup_test_with_asset(extra_asset).await;
+    let deployer = &wallets[0];
+    let user = &wallets[1];
+
+    // Base asset: 10% APR. Extra asset: 50% APR.
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_reward_rate(100)
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .register_reward_asset(extra_asset, 500)
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .deposit_rewards()
+        .call_params(CallParameters::new(1_000_000, AssetId::base(), 1_000_000))
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .deposit_rewards()
+        .call_params(CallParameters::new(1_000_000, extra_asset, 1_000_000))
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    let nft_id = create_test_nft_id(1);
+    contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .stake_nft(nft_id)
+        .call()
+        .await
+        .unwrap();
+
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY * 365).await;
+
+    let pending = contract
+        .clone()
+        .methods()
+        .get_pending_rewards(Identity::Address(user.address().into()))
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let base_pending = base_reward(&pending);
+    let extra_pending = pending
+        .iter()
+        .find(|(asset, _)| *asset == extra_asset)
+        .map(|(_, amount)| *amount)
+        .unwrap();
+
+    assert!(base_pending > 0, "Base asset rewards should accrue");
+    assert!(extra_pending > 0, "Extra asset rewards should accrue");
+    assert!(
+        extra_pending > base_pending,
+        "Extra asset's higher rate should accrue more over the same period"
+    );
+
+    // The base asset also pays the claim's gas, so only the fee-free
+    // extra asset's balance delta is asserted exactly; the base asset is
+    // instead checked the same way `test_claim_rewards` does, by
+    // confirming its pending amount drops to zero.
+    let extra_balance_before = user.get_asset_balance(&extra_asset).await.unwrap();
+
+    contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .claim_rewards()
+        .call()
+        .await
+        .unwrap();
+
+    let extra_balance_after = user.get_asset_balance(&extra_asset).await.unwrap();
+    assert_eq!(
+        extra_balance_after - extra_balance_before,
+        extra_pending,
+        "Extra asset should pay out exactly what was pending"
+    );
+
+    let pending_after = contract
+        .clone()
+        .methods()
+        .get_pending_rewards(Identity::Address(user.address().into()))
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(base_reward(&pending_after), 0, "Base asset rewards should be claimed");
+}
+
+#[tokio::test]
+async fn test_extra_asset_accrual_carries_sub_unit_remainder_across_claims() {
+    let extra_asset = AssetId::new([7u8; 32]);
+    let (contract, wallets) = setup_test_with_asset(extra_asset).await;
+    let deployer = &wallets[0];
+    let user = &wallets[1];
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .register_reward_asset(extra_asset, 1000)
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .deposit_rewards()
+        .call_params(CallParameters::new(1_000_000, extra_asset, 1_000_000))
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    let nft_id = create_test_nft_id(1);
+    contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .stake_nft(nft_id)
+        .call()
+        .await
+        .unwrap();
+
+    let extra_balance_before = user.get_asset_balance(&extra_asset).await.unwrap();
+
+    // Each individual hour is far too short to accrue a whole unit at a
+    // 100% APR (about 0.11 unit an hour), so without a carried remainder
+    // every one of these claims would pay out zero and the dust would be
+    // lost for good; carried over 24 hours it adds up to 2 units.
+    for _ in 0..24 {
+        advance_time(user.provider().unwrap(), 3600).await;
+        contract
+            .clone()
+            .with_wallet(user.clone())
+            .methods()
+            .claim_rewards()
+            .call()
+            .await
+            .unwrap();
+    }
+
+    let extra_balance_after = user.get_asset_balance(&extra_asset).await.unwrap();
+    assert!(
+        extra_balance_after > extra_balance_before,
+        "A day's worth of hourly claims should add up to a nonzero payout, not truncate to zero every time"
+    );
+}
@@ -0,0 +1,183 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+use crate::utils::*;
+
+#[tokio::test]
+async fn test_reward_brackets_step_up_with_lock_duration() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+    let user = &wallets[1];
+
+    // Base rate plus two tiers: 1 day -> 20%, 7 days -> 50%.
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_reward_rate(REWARD_RATE)
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_reward_brackets(vec![
+            Bracket {
+                min_lock_seconds: SECONDS_PER_DAY,
+                reward_rate: 200,
+            },
+            Bracket {
+                min_lock_seconds: SECONDS_PER_DAY * 7,
+                reward_rate: 500,
+            },
+        ])
+        .call()
+        .await
+        .unwrap();
+
+    let nft_id = create_test_nft_id(1);
+    contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .stake_nft(nft_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Before any bracket threshold, the base rate applies.
+    let rate = contract
+        .clone()
+        .methods()
+        .get_applicable_rate(nft_id)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(rate, REWARD_RATE, "Should start at the base rate");
+
+    // Past the 1-day threshold, the first bracket applies.
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY).await;
+    let rate = contract
+        .clone()
+        .methods()
+        .get_applicable_rate(nft_id)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(rate, 200, "Should step up to the first bracket");
+
+    // Past the 7-day threshold, the second bracket applies.
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY * 6).await;
+    let rate = contract
+        .clone()
+        .methods()
+        .get_applicable_rate(nft_id)
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(rate, 500, "Should step up to the second bracket");
+}
+
+#[tokio::test]
+async fn test_reward_brackets_must_be_strictly_increasing() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+
+    let result = contract
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_reward_brackets(vec![
+            Bracket {
+                min_lock_seconds: SECONDS_PER_DAY * 7,
+                reward_rate: 200,
+            },
+            Bracket {
+                min_lock_seconds: SECONDS_PER_DAY,
+                reward_rate: 500,
+            },
+        ])
+        .call()
+        .await;
+
+    assert!(result.is_err(), "Brackets must be strictly increasing");
+}
+
+#[tokio::test]
+async fn test_bracket_rate_only_applies_after_its_threshold_is_crossed() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+    let user = &wallets[1];
+
+    // Base rate 10%, stepping up to 50% after 1 day.
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_reward_rate(REWARD_RATE)
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_reward_brackets(vec![Bracket {
+            min_lock_seconds: SECONDS_PER_DAY,
+            reward_rate: 500,
+        }])
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .deposit_rewards()
+        .call_params(CallParameters::new(1_000_000, AssetId::base(), 1_000_000))
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    let nft_id = create_test_nft_id(1);
+    contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .stake_nft(nft_id)
+        .call()
+        .await
+        .unwrap();
+
+    // Wait 2 days without claiming: 1 day at the 10% base rate, then 1 day
+    // past the bracket threshold at 50%. A claim timed just after crossing
+    // the threshold must not have the 50% rate applied to the whole
+    // 2-day window.
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY * 2).await;
+
+    let scaled = contract
+        .clone()
+        .methods()
+        .get_pending_rewards_scaled(Identity::Address(user.address().into()))
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    let division_safety_constant: u128 = 1_000_000_000_000;
+    let seconds_per_year: u128 = 365 * 86400;
+    let expected = (SECONDS_PER_DAY as u128 * REWARD_RATE as u128 * division_safety_constant
+        + SECONDS_PER_DAY as u128 * 500 * division_safety_constant)
+        / seconds_per_year;
+
+    assert_eq!(
+        scaled as u128, expected,
+        "Only the time past the bracket threshold should earn the higher rate"
+    );
+}
@@ -1,3 +1,4 @@
+use fuels::prelude::*;
 use fuels::types::Identity;
 use crate::utils::*;
 
@@ -29,8 +30,9 @@ async fn test_reward_calculation() {
         .await
         .unwrap();
 
-    // Wait for some time
-    std::thread::sleep(std::time::Duration::from_secs(SECONDS_PER_DAY * 2));
+    // Warp the node's clock forward instead of sleeping the test thread.
+    // At a 10% rate a whole payable unit takes about 3.65 days to accrue.
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY * 10).await;
 
     // Check pending rewards
     let pending_rewards = contract
@@ -41,7 +43,7 @@ async fn test_reward_calculation() {
         .unwrap()
         .value;
 
-    assert!(pending_rewards > 0, "Should have accumulated rewards");
+    assert!(base_reward(&pending_rewards) > 0, "Should have accumulated rewards");
 }
 
 #[tokio::test]
@@ -81,7 +83,7 @@ async fn test_claim_rewards() {
         .await
         .unwrap();
 
-    std::thread::sleep(std::time::Duration::from_secs(SECONDS_PER_DAY * 2));
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY * 2).await;
 
     // Claim rewards
     contract
@@ -103,7 +105,7 @@ async fn test_claim_rewards() {
         .unwrap()
         .value;
 
-    assert_eq!(pending_rewards, 0, "Rewards should be claimed");
+    assert_eq!(base_reward(&pending_rewards), 0, "Rewards should be claimed");
 }
 
 #[tokio::test]
@@ -124,10 +126,6 @@ async fn test_reward_calculation_precise() {
 
     let nft_id = create_test_nft_id(1);
 
-    // Record initial timestamp
-    let initial_time = std::time::SystemTime::now();
-    println!("Starting reward test at: {:?}", initial_time);
-
     // Stake NFT
     contract
         .clone()
@@ -138,14 +136,33 @@ async fn test_reward_calculation_precise() {
         .await
         .unwrap();
 
-    // Simulate time passage (7 days)
-    let time_passed = SECONDS_PER_DAY * 7;
-    // Note: Add blockchain time manipulation here based on your test framework
+    // Warp the node's clock forward by a sub-day interval: short enough
+    // that the plain (truncating) reward would round to zero, which is
+    // exactly the case the fixed-point accumulator needs to get right.
+    let time_passed = 3600; // 1 hour
+    advance_time(user.provider().unwrap(), time_passed).await;
 
-    // Calculate expected rewards
-    let expected_rewards = (time_passed * 100) / (365 * SECONDS_PER_DAY); // 10% annual rate
+    const DIVISION_SAFETY_CONSTANT: u64 = 1_000_000_000_000;
+    let expected_scaled = (time_passed * 100 * DIVISION_SAFETY_CONSTANT) / (365 * SECONDS_PER_DAY);
 
-    // Check pending rewards
+    // The scaled accumulator is exact down to the fixed-point unit...
+    let pending_rewards_scaled = contract
+        .clone()
+        .methods()
+        .get_pending_rewards_scaled(Identity::Address(user.address().into()))
+        .call()
+        .await
+        .unwrap()
+        .value;
+
+    assert_eq!(
+        pending_rewards_scaled, expected_scaled,
+        "Scaled rewards calculation mismatch"
+    );
+
+    // ...while the payable view only ever returns whole units, so a short
+    // interval can legitimately read back as zero without losing the
+    // underlying accrual.
     let pending_rewards = contract
         .clone()
         .methods()
@@ -155,5 +172,9 @@ async fn test_reward_calculation_precise() {
         .unwrap()
         .value;
 
-    assert_eq!(pending_rewards, expected_rewards, "Rewards calculation mismatch");
-} 
\ No newline at end of file
+    assert_eq!(
+        base_reward(&pending_rewards),
+        expected_scaled / DIVISION_SAFETY_CONSTANT,
+        "Payable rewards should equal the truncated scaled accumulator"
+    );
+}
@@ -0,0 +1,156 @@
+use fuels::prelude::*;
+use fuels::types::Identity;
+use crate::utils::*;
+
+#[tokio::test]
+async fn test_streak_grows_and_resets() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+    let user = &wallets[1];
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_reward_rate(100)
+        .call()
+        .await
+        .unwrap();
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .deposit_rewards()
+        .call_params(CallParameters::new(1_000_000, AssetId::base(), 1_000_000))
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+
+    // A claim within 2x the 1-day interval extends the streak; 10% of
+    // the multiplier is added per streak day, capped at 3x.
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_streak_params(SECONDS_PER_DAY, 100, 3000)
+        .call()
+        .await
+        .unwrap();
+
+    let nft_id = create_test_nft_id(1);
+    contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .stake_nft(nft_id)
+        .call()
+        .await
+        .unwrap();
+
+    for expected_streak in 1..=3u64 {
+        advance_time(user.provider().unwrap(), SECONDS_PER_DAY).await;
+        contract
+            .clone()
+            .with_wallet(user.clone())
+            .methods()
+            .claim_rewards()
+            .call()
+            .await
+            .unwrap();
+
+        let streak = contract
+            .clone()
+            .methods()
+            .get_streak(Identity::Address(user.address().into()))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(streak, expected_streak, "Streak should grow by one each claim");
+    }
+
+    // Wait well past the 2x window so the streak breaks.
+    advance_time(user.provider().unwrap(), SECONDS_PER_DAY * 5).await;
+    contract
+        .clone()
+        .with_wallet(user.clone())
+        .methods()
+        .claim_rewards()
+        .call()
+        .await
+        .unwrap();
+
+    let streak = contract
+        .clone()
+        .methods()
+        .get_streak(Identity::Address(user.address().into()))
+        .call()
+        .await
+        .unwrap()
+        .value;
+    assert_eq!(streak, 1, "Streak should reset after missing the window");
+}
+
+#[tokio::test]
+async fn test_claiming_with_nothing_staked_does_not_grow_streak() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+    let user = &wallets[1];
+
+    contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_streak_params(SECONDS_PER_DAY, 100, 3000)
+        .call()
+        .await
+        .unwrap();
+
+    // Repeated claims with nothing staked must not build a streak, or a
+    // staker could farm the max multiplier before ever locking an NFT.
+    for _ in 0..3 {
+        contract
+            .clone()
+            .with_wallet(user.clone())
+            .methods()
+            .claim_rewards()
+            .call()
+            .await
+            .unwrap();
+
+        let streak = contract
+            .clone()
+            .methods()
+            .get_streak(Identity::Address(user.address().into()))
+            .call()
+            .await
+            .unwrap()
+            .value;
+        assert_eq!(streak, 0, "A staker with nothing staked should never accrue a streak");
+    }
+}
+
+#[tokio::test]
+async fn test_set_streak_params_rejects_unbounded_values() {
+    let (contract, wallets) = setup_test().await;
+    let deployer = &wallets[0];
+
+    let result = contract
+        .clone()
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_streak_params(SECONDS_PER_DAY, 10_001, 3000) // Above MAX_STREAK_MULTIPLIER
+        .call()
+        .await;
+    assert!(result.is_err(), "An unbounded step should be rejected");
+
+    let result = contract
+        .with_wallet(deployer.clone())
+        .methods()
+        .set_streak_params(SECONDS_PER_DAY, 100, 10_001) // Above MAX_STREAK_MULTIPLIER
+        .call()
+        .await;
+    assert!(result.is_err(), "An unbounded max_multiplier should be rejected");
+}
@@ -0,0 +1,7 @@
+pub mod bindings;
+pub mod helpers;
+pub mod setup;
+
+pub use bindings::*;
+pub use helpers::*;
+pub use setup::*;
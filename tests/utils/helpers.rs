@@ -1,3 +1,5 @@
+use fuels::prelude::*;
+use fuels::types::AssetId;
 use fuels::types::Bits256;
 use fuels::types::ContractId;
 use fuels::types::bech32::Bech32ContractId;
@@ -11,4 +13,37 @@ pub fn create_test_nft_id(id: u64) -> ContractId {
 
 pub fn contract_id_to_bech32(contract_id: ContractId) -> Bech32ContractId {
     Bech32ContractId::from(contract_id)
+}
+
+/// Advance the local Fuel node's clock by `seconds` instead of sleeping
+/// the test thread for real time. Produces one new block stamped
+/// `seconds` past the node's current block time, so the contract's next
+/// `std::block::timestamp()` read jumps forward deterministically.
+pub async fn advance_time(provider: &Provider, seconds: u64) {
+    let current_unix = get_block_timestamp(provider).await;
+    provider
+        .produce_blocks(1, Some(Tai64::from_unix(current_unix as i64 + seconds as i64)))
+        .await
+        .unwrap();
+}
+
+/// Pulls the base-asset amount out of a `get_pending_rewards` result, so
+/// single-asset tests don't need to scan the per-asset vector themselves.
+pub fn base_reward(pending: &[(AssetId, u64)]) -> u64 {
+    pending
+        .iter()
+        .find(|(asset, _)| *asset == AssetId::base())
+        .map(|(_, amount)| *amount)
+        .unwrap_or(0)
+}
+
+/// The node's current block timestamp as a Unix second count, so tests
+/// can compute exact elapsed-time deltas instead of relying on wall clock.
+pub async fn get_block_timestamp(provider: &Provider) -> u64 {
+    let block = provider
+        .block_by_height(provider.latest_block_height().await.unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    block.header.time.unwrap().to_unix() as u64
 } 
\ No newline at end of file
@@ -1,6 +1,6 @@
 use fuels::{
     prelude::*,
-    types::Identity,
+    types::{AssetId, Identity},
 };
 use crate::utils::bindings::NFTStakingContract;
 
@@ -40,6 +40,57 @@ pub async fn setup_test() -> (NFTStakingContract<WalletUnlocked>, Vec<WalletUnlo
     let contract_instance = NFTStakingContract::new(contract_id.clone(), deployer_wallet.clone());
 
     // Initialize the contract
+    contract_instance
+        .methods()
+        .initialize(Identity::Address(deployer_wallet.address().into()))
+        .call()
+        .await
+        .unwrap();
+
+    (contract_instance, vec![deployer_wallet, user_wallet])
+}
+
+/// Like `setup_test`, but also mints each wallet a coin of `extra_asset`
+/// so tests can exercise multi-asset reward pools without the deployer
+/// needing to forward an asset it doesn't hold.
+pub async fn setup_test_with_asset(
+    extra_asset: AssetId,
+) -> (NFTStakingContract<WalletUnlocked>, Vec<WalletUnlocked>) {
+    let asset_configs = vec![
+        AssetConfig {
+            id: AssetId::zeroed(),
+            num_coins: 1,
+            coin_amount: 1_000_000_000,
+        },
+        AssetConfig {
+            id: extra_asset,
+            num_coins: 1,
+            coin_amount: 1_000_000_000,
+        },
+    ];
+
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new_multiple_assets(2, asset_configs),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+
+    let deployer_wallet = wallets.pop().unwrap();
+    let user_wallet = wallets.pop().unwrap();
+
+    let contract_id = Contract::load_from(
+        "./out/debug/koby_staking_contract.bin",
+        LoadConfiguration::default()
+    )
+    .unwrap()
+    .deploy(&deployer_wallet, TxPolicies::default())
+    .await
+    .unwrap();
+
+    let contract_instance = NFTStakingContract::new(contract_id.clone(), deployer_wallet.clone());
+
     contract_instance
         .methods()
         .initialize(Identity::Address(deployer_wallet.address().into()))